@@ -1,24 +1,167 @@
 use std::any::TypeId;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Barrier};
 use std::thread;
+use std::time::{Duration, Instant};
 
 use crossbeam_channel::{bounded, Receiver, Select, Sender};
 use crate::event::{Event, HandleEvent, AssociatedPubEvent, AssociatedSubEvent};
+use crate::metrics::metrics;
+use crate::ring::{ring_channel, RingConsumer, RingProducer};
+use crate::store::{event_store, replay_from};
 
+/// Shared stop flag handed to every `SubApp`/`PubApp` thread. Cloning shares
+/// the same underlying flag; `ShutdownSignal::trigger` is what `Handle::shutdown`
+/// calls to ask every app thread to wind down.
+#[derive(Clone)]
+pub struct ShutdownSignal {
+    flag: Arc<AtomicBool>,
+}
+
+impl ShutdownSignal {
+    pub fn new() -> Self {
+        ShutdownSignal { flag: Arc::new(AtomicBool::new(false)) }
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.flag.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn trigger(&self) {
+        self.flag.store(true, Ordering::Release);
+    }
+}
+
+impl Default for ShutdownSignal {
+    fn default() -> Self {
+        ShutdownSignal::new()
+    }
+}
+
+/// Default outstanding-event high-water mark applied when a subscriber is
+/// added without an explicit [`BackpressurePolicy`], chosen to match the
+/// previous unconditional-block behavior at the channel's own capacity.
+const DEFAULT_HIGH_WATER_MARK: u64 = 100;
+
+/// What `send_event` does for a subscriber whose outstanding debt has
+/// exceeded its high-water mark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackpressurePolicy {
+    /// Block the publisher until the subscriber catches up (previous behavior).
+    Block,
+    /// Drop the oldest queued event to make room for the new one.
+    DropOldest,
+    /// Drop the event being sent and keep the queue as-is.
+    DropNewest,
+}
+
+/// Per-subscriber debtor/credit accounting so one lagging `SubApp` can't
+/// wedge every publisher feeding it. `outstanding` is incremented by every
+/// publisher before it enqueues and decremented by the subscriber once it
+/// finishes handling the event.
+struct SubscriberCredit {
+    outstanding: AtomicU64,
+    dropped: AtomicU64,
+    high_water_mark: u64,
+    policy: BackpressurePolicy,
+    receiver: Receiver<Arc<dyn Event + Sync + Send>>,
+}
+
+impl SubscriberCredit {
+    fn new(high_water_mark: u64, policy: BackpressurePolicy, receiver: Receiver<Arc<dyn Event + Sync + Send>>) -> Self {
+        SubscriberCredit {
+            outstanding: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            high_water_mark,
+            policy,
+            receiver,
+        }
+    }
+
+    fn send(&self, sender: &Sender<Arc<dyn Event + Sync + Send>>, event: Arc<dyn Event + Sync + Send>, event_type: TypeId) {
+        let debt = self.outstanding.fetch_add(1, Ordering::AcqRel) + 1;
+        if debt <= self.high_water_mark {
+            sender.send(event).expect("Failed to send message");
+            return;
+        }
+        match self.policy {
+            BackpressurePolicy::Block => {
+                sender.send(event).expect("Failed to send message");
+            }
+            BackpressurePolicy::DropNewest => {
+                self.outstanding.fetch_sub(1, Ordering::AcqRel);
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                metrics().record_dropped(event_type);
+            }
+            BackpressurePolicy::DropOldest => {
+                if self.receiver.try_recv().is_ok() {
+                    self.outstanding.fetch_sub(1, Ordering::AcqRel);
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    metrics().record_dropped(event_type);
+                }
+                sender.send(event).expect("Failed to send message");
+            }
+        }
+    }
+
+    fn acknowledge(&self) {
+        self.outstanding.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Live queue-depth/debt reading for a single subscriber event type, obtained
+/// from the [`SubscriberHandle`] returned by `AppEngine::add_sub_app`.
+#[derive(Debug, Clone, Copy)]
+pub struct SubscriberDebt {
+    pub outstanding: u64,
+    pub dropped: u64,
+}
+
+/// Handle to a subscriber's per-event-type credit counters, returned by
+/// `AppEngine::add_sub_app` so callers can poll queue depth/debt without
+/// holding a reference into the (consumed) engine.
+#[derive(Clone)]
+pub struct SubscriberHandle {
+    credits: HashMap<TypeId, Arc<SubscriberCredit>>,
+}
+
+impl SubscriberHandle {
+    pub fn debt(&self, type_id: TypeId) -> Option<SubscriberDebt> {
+        self.credits.get(&type_id).map(|credit| SubscriberDebt {
+            outstanding: credit.outstanding.load(Ordering::Relaxed),
+            dropped: credit.dropped.load(Ordering::Relaxed),
+        })
+    }
+}
 
 pub trait HasEventSenderProxy {
     fn get_event_sender_proxy(&mut self) -> &mut EventSenderProxy;
 }
 
+/// Gives a `PubApp` access to the engine's shutdown flag so its own
+/// `publish_event` loop can exit instead of running forever.
+pub trait HasShutdownSignal {
+    fn get_shutdown_signal(&mut self) -> &mut ShutdownSignal;
+}
+
 pub trait Publish {
     fn publish_event(&mut self);
 }
 
-type SenderRegistry = HashMap<TypeId, Vec<Sender<Arc<dyn Event + Sync + Send>>>>;
+/// One outbound hand-off for a given event type: either the default
+/// credit-backed crossbeam channel, or a wait-free SPSC ring buffer opted
+/// into for a latency-critical single-producer/single-consumer edge.
+#[derive(Clone)]
+enum Transport {
+    Channel(Sender<Arc<dyn Event + Sync + Send>>, Arc<SubscriberCredit>),
+    Ring(RingProducer<Arc<dyn Event + Sync + Send>>),
+}
+
+type SenderRegistry = HashMap<TypeId, Vec<Transport>>;
 
 pub struct EventSenderProxy {
-    sender: HashMap<TypeId, Vec<Sender<Arc<dyn Event + Sync + Send>>>>,
+    sender: SenderRegistry,
 }
 
 impl EventSenderProxy {
@@ -29,18 +172,37 @@ impl EventSenderProxy {
     #[inline]
     pub fn send_event(&self, event: Arc<dyn Event + Sync + Send>) {
         let id = event.get_event_type();
+        if let Some(store) = event_store() {
+            store.append(event.type_name(), &event.serialize());
+        }
         if self.sender.contains_key(&id) {
+            metrics().record_published(id);
             let vec = self.sender.get(&id).unwrap();
-            for elem in vec.iter() {
-                elem.send(Arc::clone(&event)).expect("Failed to send message");
+            for transport in vec.iter() {
+                match transport {
+                    Transport::Channel(sender, credit) => credit.send(sender, Arc::clone(&event), id),
+                    Transport::Ring(producer) => {
+                        // Wait-free: a full ring means the consumer fell
+                        // behind on the hot path, so we drop rather than block.
+                        if producer.push(Arc::clone(&event)).is_err() {
+                            metrics().record_dropped(id);
+                        }
+                    }
+                }
             }
         }
     }
 }
 
-pub trait SubApp: AssociatedSubEvent + AssociatedPubEvent + HandleEvent + HasEventSenderProxy + Send {}
+pub trait SubApp: AssociatedSubEvent + AssociatedPubEvent + HandleEvent + HasEventSenderProxy + Send {
+    /// Optional hook to flush state once the subscriber's loop has exited.
+    fn on_shutdown(&mut self) {}
+}
 
-pub trait PubApp: Publish + AssociatedPubEvent + HasEventSenderProxy + Send {}
+pub trait PubApp: Publish + AssociatedPubEvent + HasEventSenderProxy + HasShutdownSignal + Send {
+    /// Optional hook to flush state once `publish_event` has returned.
+    fn on_shutdown(&mut self) {}
+}
 
 struct PublisherRunner {
     sender_registry: SenderRegistry,
@@ -56,10 +218,16 @@ impl PublisherRunner {
         }
     }
 
-    fn run(&mut self) {
+    fn run(&mut self, shutdown: ShutdownSignal, start_barrier: Arc<Barrier>) {
         let proxy = self.app.get_event_sender_proxy();
         proxy.sender = self.sender_registry.clone();
+        *self.app.get_shutdown_signal() = shutdown;
+        // Don't publish a single event until every subscriber has finished
+        // replaying history, so replay can't race live traffic or double
+        // deliver an event appended mid-scan.
+        start_barrier.wait();
         self.app.publish_event();
+        self.app.on_shutdown();
     }
 
     fn get_pub_event_ids(&self) -> Vec<TypeId> {
@@ -68,43 +236,153 @@ impl PublisherRunner {
 }
 
 struct SubscriberRunner {
-    readers: Vec<Receiver<Arc<dyn Event + Sync + Send>>>,
+    readers: Vec<(TypeId, Receiver<Arc<dyn Event + Sync + Send>>)>,
     senders: HashMap<TypeId, Sender<Arc<dyn Event + Sync + Send>>>,
-    sender_registry: HashMap<TypeId, Vec<Sender<Arc<dyn Event + Sync + Send>>>>,
+    credits: HashMap<TypeId, Arc<SubscriberCredit>>,
+    ring_producers: HashMap<TypeId, RingProducer<Arc<dyn Event + Sync + Send>>>,
+    ring_consumers: HashMap<TypeId, RingConsumer<Arc<dyn Event + Sync + Send>>>,
+    sender_registry: SenderRegistry,
     app: Box<dyn SubApp>,
 }
 
 impl SubscriberRunner {
-    fn new(app: Box<dyn SubApp>) -> Self {
+    fn new(app: Box<dyn SubApp>, high_water_mark: u64, policy: BackpressurePolicy, ring_events: &[TypeId], ring_capacity: usize) -> Self {
         let mut readers = Vec::new();
         let mut senders = HashMap::new();
+        let mut credits = HashMap::new();
+        let mut ring_producers = HashMap::new();
+        let mut ring_consumers = HashMap::new();
         let sub_event_ids = app.get_associated_sub_event_ids();
         for elem in sub_event_ids.iter() {
-            let (sender, reader): (Sender<Arc<dyn Event + Sync + Send>>, Receiver<Arc<dyn Event + Sync + Send>>) = bounded(100);
-            readers.push(reader);
+            if ring_events.contains(elem) {
+                let (producer, consumer) = ring_channel(ring_capacity);
+                ring_producers.insert(*elem, producer);
+                ring_consumers.insert(*elem, consumer);
+                continue;
+            }
+            // Sized from `high_water_mark` itself -- otherwise a caller who
+            // picks `DropOldest`/`DropNewest` with a high-water mark above a
+            // hardcoded capacity would still block on `sender.send` once the
+            // channel filled, long before their chosen policy ever kicks in.
+            let (sender, reader): (Sender<Arc<dyn Event + Sync + Send>>, Receiver<Arc<dyn Event + Sync + Send>>) = bounded(high_water_mark as usize);
+            credits.insert(*elem, Arc::new(SubscriberCredit::new(high_water_mark, policy, reader.clone())));
+            readers.push((*elem, reader));
             senders.insert(*elem, sender);
         }
-        SubscriberRunner { readers, senders, sender_registry: HashMap::new(), app }
+        SubscriberRunner { readers, senders, credits, ring_producers, ring_consumers, sender_registry: HashMap::new(), app }
+    }
+
+    fn handle(&self) -> SubscriberHandle {
+        SubscriberHandle { credits: self.credits.clone() }
+    }
+
+    /// Re-feeds every stored event at or after `seq` that this app is
+    /// subscribed to, in log order, directly through `handle_event` -- run
+    /// once up front so a restarted or newly attached `SubApp` sees its
+    /// history before any live event arrives.
+    fn replay(&mut self, seq: u64) {
+        let sub_event_ids = self.app.get_associated_sub_event_ids();
+        replay_from(seq, |event| {
+            if !sub_event_ids.contains(&event.get_event_type()) {
+                return;
+            }
+            if self.app.event_filter(event.as_ref()) {
+                self.app.handle_event(event);
+            }
+        });
+    }
+
+    /// Tight spin loop used when every sub event on this app is ring-backed:
+    /// there's nothing to block on, so we check the shutdown flag every pass
+    /// instead of parking on a receiver.
+    fn run_ring_only(&mut self, shutdown: ShutdownSignal) {
+        while !shutdown.is_shutting_down() {
+            let mut delivered = false;
+            for (type_id, consumer) in self.ring_consumers.iter() {
+                if let Some(event) = consumer.pop() {
+                    if self.app.event_filter(event.as_ref()) {
+                        let start = Instant::now();
+                        self.app.handle_event(event);
+                        metrics().record_delivered(*type_id, start.elapsed());
+                    }
+                    delivered = true;
+                }
+            }
+            if !delivered {
+                std::hint::spin_loop();
+            }
+        }
     }
 
-    fn run(&mut self) {
+    fn run(&mut self, shutdown: ShutdownSignal, shutdown_rx: Receiver<()>, replay_seq: Option<u64>, start_barrier: Arc<Barrier>) {
         let proxy = self.app.get_event_sender_proxy();
         proxy.sender = self.sender_registry.clone();
+        if let Some(seq) = replay_seq {
+            self.replay(seq);
+        }
+        // Every subscriber replays before any of them waits here, and no
+        // publisher gets past its own wait on the same barrier until every
+        // subscriber has -- so live events can't arrive mid-replay or be
+        // delivered twice (once via replay, once via the live channel).
+        start_barrier.wait();
+        if self.readers.is_empty() {
+            self.run_ring_only(shutdown);
+            self.app.on_shutdown();
+            return;
+        }
         let mut sel = Select::new();
-        for r in self.readers.iter() {
+        for (_, r) in self.readers.iter() {
             sel.recv(r);
         }
+        // A dedicated shutdown branch wakes a blocked `sel.ready()` as soon
+        // as `Handle::shutdown` closes `shutdown_rx`, instead of relying on
+        // the subscriber to notice a flag on its own time.
+        let shutdown_index = sel.recv(&shutdown_rx);
+        let has_rings = !self.ring_consumers.is_empty();
         loop {
-            let index = sel.ready();
-            let reader = self.readers.get(index).unwrap();
+            for (type_id, consumer) in self.ring_consumers.iter() {
+                if let Some(event) = consumer.pop() {
+                    if self.app.event_filter(event.as_ref()) {
+                        let start = Instant::now();
+                        self.app.handle_event(event);
+                        metrics().record_delivered(*type_id, start.elapsed());
+                    }
+                }
+            }
+            if shutdown.is_shutting_down() {
+                break;
+            }
+            // A short timeout keeps the ring consumers above from starving
+            // while a mixed subscriber is otherwise parked on `sel`.
+            let index = if has_rings {
+                match sel.ready_timeout(Duration::from_micros(200)) {
+                    Ok(index) => index,
+                    Err(_) => continue,
+                }
+            } else {
+                sel.ready()
+            };
+            if index == shutdown_index {
+                break;
+            }
+            let (type_id, reader) = self.readers.get(index).unwrap();
             let event = reader.try_recv();
             if let Err(e) = event {
                 if e.is_empty() {
                     continue;
                 }
             }
-            self.app.handle_event(event.unwrap());
+            let event = event.unwrap();
+            if self.app.event_filter(event.as_ref()) {
+                let start = Instant::now();
+                self.app.handle_event(event);
+                metrics().record_delivered(*type_id, start.elapsed());
+            }
+            if let Some(credit) = self.credits.get(type_id) {
+                credit.acknowledge();
+            }
         }
+        self.app.on_shutdown();
     }
 
     fn get_sub_event_ids(&self) -> Vec<TypeId> {
@@ -120,6 +398,7 @@ impl SubscriberRunner {
 pub struct AppEngine {
     subscribers: Vec<SubscriberRunner>,
     publishers: Vec<PublisherRunner>,
+    replay_seq: Option<u64>,
 }
 
 impl AppEngine {
@@ -127,12 +406,48 @@ impl AppEngine {
         AppEngine {
             subscribers: Vec::new(),
             publishers: Vec::new(),
+            replay_seq: None,
         }
     }
 
-    pub fn add_sub_app(&mut self, sub_app: Box<dyn SubApp>) {
-        let subscriber = SubscriberRunner::new(sub_app);
+    /// Before live traffic begins, re-feed every stored event at or after
+    /// `seq` to each subscriber it's relevant to. Requires
+    /// [`crate::store::set_event_store`] to have been called first; without
+    /// an installed store this is a no-op, matching how `send_event` quietly
+    /// skips persistence when nothing was installed.
+    pub fn replay_from(mut self, seq: u64) -> Self {
+        self.replay_seq = Some(seq);
+        self
+    }
+
+    /// Adds a subscriber with the default high-water mark and a `Block`
+    /// policy, i.e. publishers stall on a slow subscriber exactly as before.
+    pub fn add_sub_app(&mut self, sub_app: Box<dyn SubApp>) -> SubscriberHandle {
+        self.add_sub_app_with_backpressure(sub_app, DEFAULT_HIGH_WATER_MARK, BackpressurePolicy::Block)
+    }
+
+    /// Adds a subscriber with an explicit debt high-water mark and drop
+    /// policy, isolating its publishers from a lagging consumer.
+    pub fn add_sub_app_with_backpressure(&mut self, sub_app: Box<dyn SubApp>, high_water_mark: u64, policy: BackpressurePolicy) -> SubscriberHandle {
+        let subscriber = SubscriberRunner::new(sub_app, high_water_mark, policy, &[], 0);
+        let handle = subscriber.handle();
         self.subscribers.push(subscriber);
+        handle
+    }
+
+    /// Adds a subscriber whose `ring_events` are delivered over a lock-free
+    /// SPSC ring buffer (capacity rounded up to a power of two) instead of
+    /// the default crossbeam channel, for sub-microsecond, allocation-stable
+    /// hand-off on a latency-critical single-producer edge (e.g.
+    /// `KlinePublisher` -> `MarketMakerApp`). This only holds up if exactly
+    /// one publisher feeds each listed event type -- multi-producer edges
+    /// should keep the default crossbeam transport. Any other sub events on
+    /// the same app keep using the default credit-backed crossbeam channel.
+    pub fn add_sub_app_with_ring_transport(&mut self, sub_app: Box<dyn SubApp>, ring_events: &[TypeId], ring_capacity: usize) -> SubscriberHandle {
+        let subscriber = SubscriberRunner::new(sub_app, DEFAULT_HIGH_WATER_MARK, BackpressurePolicy::Block, ring_events, ring_capacity);
+        let handle = subscriber.handle();
+        self.subscribers.push(subscriber);
+        handle
     }
 
     pub fn add_pub_app(&mut self, pub_app: Box<dyn PubApp>) {
@@ -141,16 +456,46 @@ impl AppEngine {
     }
 
     fn build_channel(&mut self) {
-        let mut sub_registry = HashMap::new();
+        let mut sub_registry: SenderRegistry = HashMap::new();
+        let mut ring_type_ids: std::collections::HashSet<TypeId> = std::collections::HashSet::new();
         for elem in self.subscribers.iter_mut() {
             for (type_id, sender) in elem.senders.iter() {
-                if sub_registry.contains_key(type_id) {
-                    let vec: &mut Vec<Sender<Arc<dyn Event + Sync + Send>>> = sub_registry.get_mut(type_id).unwrap();
-                    vec.push(sender.clone());
-                } else {
-                    sub_registry.insert(*type_id, vec![sender.clone()]);
+                let credit = elem.credits.get(type_id).unwrap().clone();
+                sub_registry.entry(*type_id).or_insert_with(Vec::new).push(Transport::Channel(sender.clone(), credit));
+            }
+            for (type_id, producer) in elem.ring_producers.iter() {
+                sub_registry.entry(*type_id).or_insert_with(Vec::new).push(Transport::Ring(producer.clone()));
+                ring_type_ids.insert(*type_id);
+            }
+        }
+        // A ring transport is only sound with exactly one producer: a second
+        // thread cloning the same `RingProducer` would push into the same
+        // slots with no synchronization between the two. Refuse to wire up
+        // the graph rather than let that race happen at runtime.
+        if !ring_type_ids.is_empty() {
+            let mut producer_counts: HashMap<TypeId, u32> = HashMap::new();
+            for elem in self.publishers.iter() {
+                for id in elem.get_pub_event_ids() {
+                    if ring_type_ids.contains(&id) {
+                        *producer_counts.entry(id).or_insert(0) += 1;
+                    }
                 }
             }
+            for elem in self.subscribers.iter() {
+                for id in elem.get_pub_event_ids() {
+                    if ring_type_ids.contains(&id) {
+                        *producer_counts.entry(id).or_insert(0) += 1;
+                    }
+                }
+            }
+            for (type_id, count) in producer_counts {
+                assert!(
+                    count <= 1,
+                    "ring-backed event type {:?} has {} publishers feeding it, but a ring transport only supports a single producer -- use add_sub_app_with_backpressure for multi-producer edges instead",
+                    type_id,
+                    count
+                );
+            }
         }
         for elem in self.publishers.iter_mut() {
             let pub_event_ids = elem.get_pub_event_ids();
@@ -162,40 +507,158 @@ impl AppEngine {
         }
     }
 
-    fn set_sender(sub_registry: &HashMap<TypeId, Vec<Sender<Arc<dyn Event + Sync + Send>>>>,
-                  sender_registry: &mut SenderRegistry, pub_event_ids: Vec<TypeId>) {
+    fn set_sender(sub_registry: &SenderRegistry, sender_registry: &mut SenderRegistry, pub_event_ids: Vec<TypeId>) {
         for each in pub_event_ids.iter() {
-            if sub_registry.contains_key(each) {
-                let vec = sub_registry.get(each).unwrap();
-                for sender in vec.iter() {
-                    if sender_registry.contains_key(each) {
-                        let vec = sender_registry.get_mut(each).unwrap();
-                        vec.push(sender.clone());
-                    } else {
-                        sender_registry.insert(*each, vec![sender.clone()]);
-                    }
+            if let Some(vec) = sub_registry.get(each) {
+                for entry in vec.iter() {
+                    sender_registry.entry(*each).or_insert_with(Vec::new).push(entry.clone());
                 }
             }
         }
     }
 
-    pub fn run(mut self) {
+    /// Starts every registered subscriber and publisher on its own thread and
+    /// returns a [`Handle`] for requesting a graceful stop and waiting for
+    /// every thread to exit. Every subscriber gets its own shutdown channel so
+    /// `Handle::shutdown` can wake a blocked `Select` without touching the
+    /// others; all subscribers and publishers share the same `ShutdownSignal`
+    /// flag so a spinning ring-only loop notices the same request.
+    pub fn run(mut self) -> Handle {
         self.build_channel();
+        let shutdown = ShutdownSignal::new();
+        let mut shutdown_senders = Vec::new();
         let mut tasks = Vec::new();
+        let replay_seq = self.replay_seq;
+        // Every subscriber and publisher thread waits here once before doing
+        // any real work, so every subscriber's replay is guaranteed to finish
+        // before any publisher sends a single live event.
+        let start_barrier = Arc::new(Barrier::new(self.subscribers.len() + self.publishers.len()));
         for mut subscriber in self.subscribers {
+            let (shutdown_tx, shutdown_rx) = bounded::<()>(1);
+            shutdown_senders.push(shutdown_tx);
+            let subscriber_shutdown = shutdown.clone();
+            let subscriber_barrier = start_barrier.clone();
             let task = thread::spawn(move || {
-                subscriber.run();
+                subscriber.run(subscriber_shutdown, shutdown_rx, replay_seq, subscriber_barrier);
             });
-            tasks.push(task);
+            tasks.push(("subscriber".to_string(), task));
         }
         for mut publisher in self.publishers {
+            let publisher_shutdown = shutdown.clone();
+            let publisher_barrier = start_barrier.clone();
             let task = thread::spawn(move || {
-                publisher.run();
+                publisher.run(publisher_shutdown, publisher_barrier);
             });
-            tasks.push(task);
+            tasks.push(("publisher".to_string(), task));
         }
-        for task in tasks {
-            task.join().unwrap();
+        Handle { shutdown, shutdown_senders, tasks }
+    }
+}
+
+/// Returned by [`AppEngine::run`]; the only way to stop or wait on the
+/// subscriber/publisher threads it started, replacing the old fire-and-forget
+/// `run` that blocked forever and could only be killed by aborting the
+/// process.
+pub struct Handle {
+    shutdown: ShutdownSignal,
+    shutdown_senders: Vec<Sender<()>>,
+    tasks: Vec<(String, thread::JoinHandle<()>)>,
+}
+
+impl Handle {
+    /// Asks every app thread to wind down: flips the shared flag (noticed by
+    /// ring-only spin loops and checked between `Select` wakeups) and drops
+    /// the per-subscriber shutdown senders so any thread parked in `Select`
+    /// wakes immediately instead of waiting for its next event or timeout.
+    pub fn shutdown(&mut self) {
+        self.shutdown.trigger();
+        self.shutdown_senders.clear();
+    }
+
+    /// Joins every app thread, collecting a description of any that panicked
+    /// instead of propagating the panic and aborting the other joins.
+    pub fn join(self) -> Vec<String> {
+        let mut panics = Vec::new();
+        for (kind, task) in self.tasks {
+            if let Err(payload) = task.join() {
+                let reason = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string());
+                panics.push(format!("{kind} thread panicked: {reason}"));
+            }
+        }
+        panics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestEvent;
+
+    impl Event for TestEvent {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
         }
+
+        fn type_name(&self) -> &'static str {
+            "TestEvent"
+        }
+
+        fn serialize(&self) -> Vec<u8> {
+            Vec::new()
+        }
+    }
+
+    fn test_event() -> Arc<dyn Event + Sync + Send> {
+        Arc::new(TestEvent)
+    }
+
+    #[test]
+    fn block_policy_sends_past_the_high_water_mark() {
+        let (sender, receiver) = bounded(4);
+        let credit = SubscriberCredit::new(1, BackpressurePolicy::Block, receiver.clone());
+        credit.send(&sender, test_event(), TypeId::of::<TestEvent>());
+        credit.send(&sender, test_event(), TypeId::of::<TestEvent>());
+        // Over the high-water mark, but Block still enqueues instead of dropping.
+        assert_eq!(receiver.len(), 2);
+        assert_eq!(credit.outstanding.load(Ordering::Relaxed), 2);
+        assert_eq!(credit.dropped.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn drop_newest_discards_the_incoming_event_once_over_the_mark() {
+        let (sender, receiver) = bounded(4);
+        let credit = SubscriberCredit::new(1, BackpressurePolicy::DropNewest, receiver.clone());
+        credit.send(&sender, test_event(), TypeId::of::<TestEvent>());
+        credit.send(&sender, test_event(), TypeId::of::<TestEvent>());
+        assert_eq!(receiver.len(), 1);
+        assert_eq!(credit.outstanding.load(Ordering::Relaxed), 1);
+        assert_eq!(credit.dropped.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn drop_oldest_makes_room_by_discarding_the_queued_event() {
+        let (sender, receiver) = bounded(4);
+        let credit = SubscriberCredit::new(1, BackpressurePolicy::DropOldest, receiver.clone());
+        credit.send(&sender, test_event(), TypeId::of::<TestEvent>());
+        credit.send(&sender, test_event(), TypeId::of::<TestEvent>());
+        // The first event was dropped to make room; only the second is queued.
+        assert_eq!(receiver.len(), 1);
+        assert_eq!(credit.outstanding.load(Ordering::Relaxed), 1);
+        assert_eq!(credit.dropped.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn acknowledge_decrements_outstanding_debt() {
+        let (sender, receiver) = bounded(4);
+        let credit = SubscriberCredit::new(10, BackpressurePolicy::Block, receiver);
+        credit.send(&sender, test_event(), TypeId::of::<TestEvent>());
+        credit.send(&sender, test_event(), TypeId::of::<TestEvent>());
+        credit.acknowledge();
+        assert_eq!(credit.outstanding.load(Ordering::Relaxed), 1);
     }
 }
@@ -0,0 +1,266 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::task::JoinHandle;
+use tokio_stream::{StreamExt, StreamMap};
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::app::{HasShutdownSignal, ShutdownSignal};
+use crate::event::{AssociatedPubEvent, AssociatedSubEvent, AsyncHandleEvent, Event};
+
+pub trait HasAsyncEventSenderProxy {
+    fn get_async_event_sender_proxy(&mut self) -> &mut AsyncEventSenderProxy;
+}
+
+#[async_trait]
+pub trait AsyncPublish {
+    async fn publish_event(&mut self);
+}
+
+type AsyncSenderRegistry = HashMap<TypeId, Vec<Sender<Arc<dyn Event + Sync + Send>>>>;
+
+#[derive(Clone)]
+pub struct AsyncEventSenderProxy {
+    sender: AsyncSenderRegistry,
+}
+
+impl AsyncEventSenderProxy {
+    pub fn new() -> Self {
+        AsyncEventSenderProxy { sender: HashMap::new() }
+    }
+
+    #[inline]
+    pub async fn send_event(&self, event: Arc<dyn Event + Sync + Send>) {
+        let id = event.get_event_type();
+        if let Some(vec) = self.sender.get(&id) {
+            // Spawn one send per consumer instead of awaiting them in
+            // sequence, so a single slow or full subscriber channel can't
+            // stall delivery to every other consumer of this event type --
+            // the whole point of running subscribers as lightweight tasks
+            // instead of threads.
+            let mut sends = Vec::with_capacity(vec.len());
+            for elem in vec.iter() {
+                let sender = elem.clone();
+                let event = Arc::clone(&event);
+                sends.push(tokio::spawn(async move {
+                    sender.send(event).await.expect("Failed to send message");
+                }));
+            }
+            for send in sends {
+                send.await.expect("event fan-out task panicked");
+            }
+        }
+    }
+}
+
+pub trait AsyncSubApp: AssociatedSubEvent + AssociatedPubEvent + AsyncHandleEvent + HasAsyncEventSenderProxy + Send {}
+
+pub trait AsyncPubApp: AsyncPublish + AssociatedPubEvent + HasAsyncEventSenderProxy + HasShutdownSignal + Send {}
+
+struct AsyncPublisherRunner {
+    sender_registry: AsyncSenderRegistry,
+    app: Box<dyn AsyncPubApp>,
+}
+
+impl AsyncPublisherRunner {
+    fn new(app: Box<dyn AsyncPubApp>) -> Self {
+        AsyncPublisherRunner {
+            sender_registry: HashMap::new(),
+            app,
+        }
+    }
+
+    async fn run(&mut self, shutdown: ShutdownSignal) {
+        let proxy = self.app.get_async_event_sender_proxy();
+        proxy.sender = self.sender_registry.clone();
+        *self.app.get_shutdown_signal() = shutdown;
+        self.app.publish_event().await;
+    }
+
+    fn get_pub_event_ids(&self) -> Vec<TypeId> {
+        self.app.get_associated_pub_event_ids()
+    }
+}
+
+struct AsyncSubscriberRunner {
+    senders: HashMap<TypeId, Sender<Arc<dyn Event + Sync + Send>>>,
+    receivers: HashMap<TypeId, Receiver<Arc<dyn Event + Sync + Send>>>,
+    sender_registry: AsyncSenderRegistry,
+    app: Box<dyn AsyncSubApp>,
+}
+
+impl AsyncSubscriberRunner {
+    fn new(app: Box<dyn AsyncSubApp>) -> Self {
+        let mut senders = HashMap::new();
+        let mut receivers = HashMap::new();
+        let sub_event_ids = app.get_associated_sub_event_ids();
+        for elem in sub_event_ids.iter() {
+            let (sender, receiver): (Sender<Arc<dyn Event + Sync + Send>>, Receiver<Arc<dyn Event + Sync + Send>>) = channel(100);
+            senders.insert(*elem, sender);
+            receivers.insert(*elem, receiver);
+        }
+        AsyncSubscriberRunner { senders, receivers, sender_registry: HashMap::new(), app }
+    }
+
+    async fn run(&mut self, shutdown: ShutdownSignal) {
+        let proxy = self.app.get_async_event_sender_proxy();
+        proxy.sender = self.sender_registry.clone();
+        let mut streams = StreamMap::new();
+        for (type_id, receiver) in self.receivers.drain() {
+            streams.insert(type_id, ReceiverStream::new(receiver));
+        }
+        loop {
+            if shutdown.is_shutting_down() {
+                break;
+            }
+            // A periodic tick alongside the stream read means a subscriber
+            // with no traffic still notices `AsyncHandle::shutdown` promptly
+            // instead of waiting forever on a receiver that may never yield
+            // again.
+            tokio::select! {
+                next = streams.next() => {
+                    match next {
+                        Some((_, event)) => {
+                            if self.app.event_filter(event.as_ref()) {
+                                self.app.handle_event(event).await;
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                _ = tokio::time::sleep(Duration::from_millis(100)) => {
+                    if shutdown.is_shutting_down() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_pub_event_ids(&self) -> Vec<TypeId> {
+        self.app.get_associated_pub_event_ids()
+    }
+}
+
+/// Drives `AsyncSubApp`/`AsyncPubApp` as futures on a Tokio runtime instead of
+/// one OS thread per app. Idle subscribers park on their receivers rather than
+/// busy-spinning, so hundreds of lightweight consumer apps can share a small
+/// worker pool.
+pub struct AsyncAppEngine {
+    subscribers: Vec<AsyncSubscriberRunner>,
+    publishers: Vec<AsyncPublisherRunner>,
+}
+
+impl AsyncAppEngine {
+    pub fn new() -> Self {
+        AsyncAppEngine {
+            subscribers: Vec::new(),
+            publishers: Vec::new(),
+        }
+    }
+
+    pub fn add_async_sub_app(&mut self, sub_app: Box<dyn AsyncSubApp>) {
+        let subscriber = AsyncSubscriberRunner::new(sub_app);
+        self.subscribers.push(subscriber);
+    }
+
+    pub fn add_async_pub_app(&mut self, pub_app: Box<dyn AsyncPubApp>) {
+        let publisher = AsyncPublisherRunner::new(pub_app);
+        self.publishers.push(publisher);
+    }
+
+    fn build_channel(&mut self) {
+        let mut sub_registry = HashMap::new();
+        for elem in self.subscribers.iter() {
+            for (type_id, sender) in elem.senders.iter() {
+                sub_registry.entry(*type_id).or_insert_with(Vec::new).push(sender.clone());
+            }
+        }
+        for elem in self.publishers.iter_mut() {
+            let pub_event_ids = elem.get_pub_event_ids();
+            Self::set_sender(&sub_registry, &mut elem.sender_registry, pub_event_ids);
+        }
+        for elem in self.subscribers.iter_mut() {
+            let pub_event_ids = elem.get_pub_event_ids();
+            Self::set_sender(&sub_registry, &mut elem.sender_registry, pub_event_ids);
+        }
+    }
+
+    fn set_sender(sub_registry: &AsyncSenderRegistry, sender_registry: &mut AsyncSenderRegistry, pub_event_ids: Vec<TypeId>) {
+        for each in pub_event_ids.iter() {
+            if let Some(vec) = sub_registry.get(each) {
+                for sender in vec.iter() {
+                    sender_registry.entry(*each).or_insert_with(Vec::new).push(sender.clone());
+                }
+            }
+        }
+    }
+
+    /// Spawns every registered subscriber and publisher as its own task and
+    /// returns an [`AsyncHandle`] for requesting a graceful stop and waiting
+    /// on all of them -- mirroring [`crate::app::AppEngine::run`]/[`crate::app::Handle`]
+    /// instead of awaiting every task in sequence, which let one panicking
+    /// task abort `run()` and abandon every other task still in flight.
+    pub async fn run(mut self) -> AsyncHandle {
+        self.build_channel();
+        let shutdown = ShutdownSignal::new();
+        let mut tasks: Vec<(String, JoinHandle<()>)> = Vec::new();
+        for mut subscriber in self.subscribers {
+            let subscriber_shutdown = shutdown.clone();
+            tasks.push(("subscriber".to_string(), tokio::spawn(async move {
+                subscriber.run(subscriber_shutdown).await;
+            })));
+        }
+        for mut publisher in self.publishers {
+            let publisher_shutdown = shutdown.clone();
+            tasks.push(("publisher".to_string(), tokio::spawn(async move {
+                publisher.run(publisher_shutdown).await;
+            })));
+        }
+        AsyncHandle { shutdown, tasks }
+    }
+}
+
+/// Returned by [`AsyncAppEngine::run`]; the only way to stop or wait on the
+/// subscriber/publisher tasks it started, replacing the old fire-and-forget
+/// `run` that could only be killed by aborting the process and that dropped
+/// every other task's result the moment one task panicked.
+pub struct AsyncHandle {
+    shutdown: ShutdownSignal,
+    tasks: Vec<(String, JoinHandle<()>)>,
+}
+
+impl AsyncHandle {
+    /// Asks every app task to wind down: subscribers notice on their next
+    /// polling tick, and publishers notice through their own `ShutdownSignal`
+    /// the same way a sync `PubApp` does.
+    pub fn shutdown(&self) {
+        self.shutdown.trigger();
+    }
+
+    /// Awaits every app task, collecting a description of any that panicked
+    /// instead of propagating the panic and abandoning the other tasks.
+    pub async fn join(self) -> Vec<String> {
+        let mut panics = Vec::new();
+        for (kind, task) in self.tasks {
+            if let Err(err) = task.await {
+                let reason = if err.is_panic() {
+                    let payload = err.into_panic();
+                    payload
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| payload.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "unknown panic".to_string())
+                } else {
+                    "task cancelled".to_string()
+                };
+                panics.push(format!("{kind} task panicked: {reason}"));
+            }
+        }
+        panics
+    }
+}
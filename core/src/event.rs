@@ -1,8 +1,21 @@
 use std::any::{Any, TypeId};
 use std::sync::Arc;
 
+use async_trait::async_trait;
+
 pub trait AssociatedSubEvent {
     fn get_associated_sub_event_ids(&self) -> Vec<TypeId>;
+
+    /// Content-based filter evaluated before a subscribed event is delivered
+    /// to `handle_event`, letting several subscribers share one event type
+    /// while each only receiving the slice they care about. Defaults to
+    /// accepting every event; `#[sub_filter(EventType, field <op> value, ...)]`
+    /// on a `#[derive(SubApp)]` struct generates a downcast-and-test override.
+    /// `<op>` is one of `=`/`==`, `!=`, `<`, `<=`, `>`, `>=`, so numeric
+    /// fields support range filters like `#[sub_filter(Price, price > 100.0)]`.
+    fn event_filter(&self, _event: &dyn Event) -> bool {
+        true
+    }
 }
 
 pub trait AssociatedPubEvent {
@@ -15,9 +28,27 @@ pub trait HandleEvent {
     fn handle_event(&mut self, event: Arc<dyn Event + Sync + Send>);
 }
 
+/// Async counterpart of `HandleEvent` for apps driven as futures on a Tokio
+/// runtime instead of a dedicated OS thread.
+#[async_trait]
+pub trait AsyncHandleEvent {
+    async fn handle_event(&mut self, event: Arc<dyn Event + Sync + Send>);
+}
+
 pub trait Event {
     fn get_event_type(&self) -> TypeId where Self: 'static {
         TypeId::of::<Self>()
     }
     fn as_any(&self) -> &dyn Any;
+
+    /// Stable name used to key this event in the durable log and on the
+    /// network relay wire. Unlike `TypeId`, it's the same across separate
+    /// binaries and rebuilds, which is what replay and cross-process routing
+    /// rely on. `#[derive(EventType)]` fills this in with the struct's name.
+    fn type_name(&self) -> &'static str;
+
+    /// Serializes this event for the durable log and network relay.
+    /// `#[derive(EventType)]` generates this via `bincode`, so any struct
+    /// deriving `EventType` must also derive `serde::Serialize`.
+    fn serialize(&self) -> Vec<u8>;
 }
\ No newline at end of file
@@ -1,5 +1,16 @@
 pub mod event;
 pub mod app;
+pub mod async_app;
+pub mod ring;
+pub mod metrics;
+pub mod registry;
+pub mod store;
+pub mod relay;
+
+/// Re-exported so `#[derive(EventType)]`-generated code can call
+/// `event_flow::inventory::submit!` without requiring `inventory` as a
+/// direct dependency of every crate that defines an event.
+pub use inventory;
 
 #[macro_export]
 macro_rules! sub_event {
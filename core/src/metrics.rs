@@ -0,0 +1,345 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::thread;
+use std::time::Duration;
+
+use arc_swap::ArcSwap;
+use crossbeam_channel::{unbounded, Receiver, RecvTimeoutError, Sender};
+
+use crate::ring::{ring_channel, RingConsumer, RingProducer};
+
+/// Longest run of per-event-type latency samples kept for percentile
+/// calculations. Bounded so the aggregator's memory use doesn't grow with
+/// uptime; old samples are dropped in FIFO order like a ring buffer.
+const MAX_LATENCY_SAMPLES: usize = 4096;
+
+/// Capacity of each calling thread's own ring of pending metric records. A
+/// thread that outpaces the aggregator drops records past this point rather
+/// than growing without bound, same as the rest of the transport layer.
+const THREAD_RING_CAPACITY: usize = 1024;
+
+/// Runtime-tunable knobs for the metrics pipeline, swapped atomically via
+/// [`Metrics::reconfigure`] so sampling can be adjusted without restarting
+/// `AppEngine`.
+#[derive(Debug, Clone, Copy)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    /// Fraction of events recorded, in `[0.0, 1.0]`. `1.0` records every
+    /// event; lower values trade precision for overhead on very hot paths.
+    pub sample_rate: f64,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        MetricsConfig { enabled: true, sample_rate: 1.0 }
+    }
+}
+
+enum MetricRecord {
+    Published { event_type: TypeId },
+    Delivered { event_type: TypeId, latency: Duration },
+    Dropped { event_type: TypeId },
+}
+
+#[derive(Default)]
+struct TypeCounters {
+    published: AtomicU64,
+    delivered: AtomicU64,
+    dropped: AtomicU64,
+    latencies: RwLock<VecDeque<Duration>>,
+}
+
+/// Point-in-time read of a single event type's counters and latency
+/// percentiles, as returned by [`Metrics::snapshot`].
+#[derive(Debug, Clone, Copy)]
+pub struct TypeMetricsSnapshot {
+    pub published: u64,
+    pub delivered: u64,
+    pub dropped: u64,
+    pub p50_latency: Option<Duration>,
+    pub p99_latency: Option<Duration>,
+}
+
+/// Operator-facing snapshot of current throughput and tail latency per event
+/// type, covering every type seen by `send_event`/`handle_event` so far.
+#[derive(Debug, Clone)]
+pub struct MetricsSnapshot {
+    pub per_type: HashMap<TypeId, TypeMetricsSnapshot>,
+}
+
+thread_local! {
+    /// Each thread that ever records a metric gets its own wait-free SPSC
+    /// ring the first time it does, registered with the aggregator via
+    /// `Metrics::register`. `RingProducer` can't be shared across threads
+    /// (see its `Clone` doc comment), so one ring per thread -- not one
+    /// shared ring -- is what keeps this sound under concurrent
+    /// publishers/subscribers instead of racing on a single tail index.
+    static THREAD_RING: RefCell<Option<RingProducer<MetricRecord>>> = const { RefCell::new(None) };
+}
+
+/// Lock-free-on-the-hot-path metrics pipeline: `record_*` pushes onto the
+/// calling thread's own ring buffer and returns immediately, so
+/// instrumenting `send_event`/`handle_event` never blocks a publisher or
+/// subscriber. A dedicated aggregator thread polls every registered ring and
+/// folds records into per-event-type counters and latency samples that
+/// `snapshot` reads.
+pub struct Metrics {
+    register: Sender<RingConsumer<MetricRecord>>,
+    config: Arc<ArcSwap<MetricsConfig>>,
+    counters: Arc<RwLock<HashMap<TypeId, TypeCounters>>>,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let (register, register_rx): (Sender<RingConsumer<MetricRecord>>, Receiver<RingConsumer<MetricRecord>>) = unbounded();
+        let counters: Arc<RwLock<HashMap<TypeId, TypeCounters>>> = Arc::new(RwLock::new(HashMap::new()));
+        let aggregator_counters = counters.clone();
+        thread::spawn(move || Self::aggregate(register_rx, aggregator_counters));
+        Metrics {
+            register,
+            config: Arc::new(ArcSwap::from_pointee(MetricsConfig::default())),
+            counters,
+        }
+    }
+
+    fn aggregate(register_rx: Receiver<RingConsumer<MetricRecord>>, counters: Arc<RwLock<HashMap<TypeId, TypeCounters>>>) {
+        let mut rings: Vec<RingConsumer<MetricRecord>> = Vec::new();
+        loop {
+            let mut delivered = false;
+            for ring in rings.iter() {
+                while let Some(record) = ring.pop() {
+                    delivered = true;
+                    Self::fold(&counters, record);
+                }
+            }
+            // A newly registered per-thread ring shows up here; block
+            // briefly instead of busy-spinning the aggregator thread when
+            // every known ring was empty this pass.
+            let timeout = if delivered { Duration::from_millis(0) } else { Duration::from_millis(5) };
+            match register_rx.recv_timeout(timeout) {
+                Ok(ring) => rings.push(ring),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+    }
+
+    fn fold(counters: &Arc<RwLock<HashMap<TypeId, TypeCounters>>>, record: MetricRecord) {
+        let event_type = match &record {
+            MetricRecord::Published { event_type } => *event_type,
+            MetricRecord::Delivered { event_type, .. } => *event_type,
+            MetricRecord::Dropped { event_type } => *event_type,
+        };
+        let mut guard = counters.write().expect("metrics counters lock poisoned");
+        let entry = guard.entry(event_type).or_default();
+        match record {
+            MetricRecord::Published { .. } => {
+                entry.published.fetch_add(1, Ordering::Relaxed);
+            }
+            MetricRecord::Delivered { latency, .. } => {
+                entry.delivered.fetch_add(1, Ordering::Relaxed);
+                let mut latencies = entry.latencies.write().expect("latency samples lock poisoned");
+                if latencies.len() == MAX_LATENCY_SAMPLES {
+                    latencies.pop_front();
+                }
+                latencies.push_back(latency);
+            }
+            MetricRecord::Dropped { .. } => {
+                entry.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Pushes `record` onto the calling thread's own ring, registering one
+    /// with the aggregator the first time this thread records anything.
+    /// Non-blocking and bounded: a thread that outpaces the aggregator drops
+    /// records past `THREAD_RING_CAPACITY` instead of blocking the caller or
+    /// growing without bound.
+    fn push(&self, record: MetricRecord) {
+        THREAD_RING.with(|cell| {
+            let mut slot = cell.borrow_mut();
+            let producer = slot.get_or_insert_with(|| {
+                let (producer, consumer) = ring_channel(THREAD_RING_CAPACITY);
+                let _ = self.register.send(consumer);
+                producer
+            });
+            let _ = producer.push(record);
+        });
+    }
+
+    /// Hot-reloads sampling/enablement without restarting `AppEngine`.
+    pub fn reconfigure(&self, config: MetricsConfig) {
+        self.config.store(Arc::new(config));
+    }
+
+    #[inline]
+    fn sampled(&self) -> bool {
+        let config = self.config.load();
+        config.enabled && (config.sample_rate >= 1.0 || rand_unit() < config.sample_rate)
+    }
+
+    #[inline]
+    pub fn record_published(&self, event_type: TypeId) {
+        if self.sampled() {
+            self.push(MetricRecord::Published { event_type });
+        }
+    }
+
+    #[inline]
+    pub fn record_delivered(&self, event_type: TypeId, latency: Duration) {
+        if self.sampled() {
+            self.push(MetricRecord::Delivered { event_type, latency });
+        }
+    }
+
+    #[inline]
+    pub fn record_dropped(&self, event_type: TypeId) {
+        if self.sampled() {
+            self.push(MetricRecord::Dropped { event_type });
+        }
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let guard = self.counters.read().expect("metrics counters lock poisoned");
+        let per_type = guard
+            .iter()
+            .map(|(type_id, counters)| {
+                let mut samples: Vec<Duration> = counters
+                    .latencies
+                    .read()
+                    .expect("latency samples lock poisoned")
+                    .iter()
+                    .copied()
+                    .collect();
+                samples.sort_unstable();
+                let snapshot = TypeMetricsSnapshot {
+                    published: counters.published.load(Ordering::Relaxed),
+                    delivered: counters.delivered.load(Ordering::Relaxed),
+                    dropped: counters.dropped.load(Ordering::Relaxed),
+                    p50_latency: percentile(&samples, 0.50),
+                    p99_latency: percentile(&samples, 0.99),
+                };
+                (*type_id, snapshot)
+            })
+            .collect();
+        MetricsSnapshot { per_type }
+    }
+}
+
+fn percentile(sorted_samples: &[Duration], p: f64) -> Option<Duration> {
+    if sorted_samples.is_empty() {
+        return None;
+    }
+    let rank = ((sorted_samples.len() - 1) as f64 * p).round() as usize;
+    sorted_samples.get(rank).copied()
+}
+
+/// Cheap, dependency-free source of jitter for probabilistic sampling; not
+/// cryptographic, just enough to decorrelate sampling decisions across calls.
+fn rand_unit() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or(0);
+    (nanos % 1_000_000) as f64 / 1_000_000.0
+}
+
+static METRICS: OnceLock<Metrics> = OnceLock::new();
+
+/// Process-wide metrics pipeline shared by every `EventSenderProxy` and
+/// `SubscriberRunner`.
+pub fn metrics() -> &'static Metrics {
+    METRICS.get_or_init(Metrics::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::any::TypeId;
+
+    struct Marker;
+
+    /// Polls `snapshot` until any activity for `event_type` shows up or
+    /// `attempts` is exhausted, since recording is async by design (push onto
+    /// a per-thread ring, fold on the background aggregator thread). It does
+    /// not wait for every pending record to be folded.
+    fn wait_for_snapshot(metrics: &Metrics, event_type: TypeId, attempts: u32) -> TypeMetricsSnapshot {
+        for _ in 0..attempts {
+            if let Some(snapshot) = metrics.snapshot().per_type.get(&event_type) {
+                if snapshot.published + snapshot.delivered + snapshot.dropped > 0 {
+                    return *snapshot;
+                }
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        metrics.snapshot().per_type.get(&event_type).copied().unwrap_or(TypeMetricsSnapshot {
+            published: 0,
+            delivered: 0,
+            dropped: 0,
+            p50_latency: None,
+            p99_latency: None,
+        })
+    }
+
+    #[test]
+    fn record_published_is_reflected_in_snapshot() {
+        let metrics = Metrics::new();
+        let event_type = TypeId::of::<Marker>();
+        metrics.record_published(event_type);
+        let snapshot = wait_for_snapshot(&metrics, event_type, 50);
+        assert_eq!(snapshot.published, 1);
+    }
+
+    #[test]
+    fn record_delivered_tracks_latency_percentiles() {
+        let metrics = Metrics::new();
+        let event_type = TypeId::of::<Marker>();
+        for millis in [10, 20, 30, 40, 50] {
+            metrics.record_delivered(event_type, Duration::from_millis(millis));
+        }
+        let snapshot = wait_for_snapshot(&metrics, event_type, 50);
+        assert_eq!(snapshot.delivered, 5);
+        assert_eq!(snapshot.p50_latency, Some(Duration::from_millis(30)));
+    }
+
+    #[test]
+    fn record_dropped_is_reflected_in_snapshot() {
+        let metrics = Metrics::new();
+        let event_type = TypeId::of::<Marker>();
+        metrics.record_dropped(event_type);
+        let snapshot = wait_for_snapshot(&metrics, event_type, 50);
+        assert_eq!(snapshot.dropped, 1);
+    }
+
+    #[test]
+    fn records_from_multiple_threads_are_all_aggregated() {
+        let metrics = Arc::new(Metrics::new());
+        let event_type = TypeId::of::<Marker>();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let metrics = metrics.clone();
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        metrics.record_published(event_type);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().expect("recorder thread panicked");
+        }
+        let mut snapshot = wait_for_snapshot(&metrics, event_type, 50);
+        // Keep polling a little longer than `wait_for_snapshot`'s "any activity
+        // seen" threshold, since four threads' rings can drain across more
+        // than one aggregator pass.
+        for _ in 0..50 {
+            if snapshot.published == 40 {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+            snapshot = wait_for_snapshot(&metrics, event_type, 1);
+        }
+        assert_eq!(snapshot.published, 40);
+    }
+}
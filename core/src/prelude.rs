@@ -1,4 +1,8 @@
 pub use std::any::{Any, TypeId};
-pub use crate::event::{AssociatedSubEvent, Event, AssociatedPubEvent, HandleEvent};
-pub use crate::app::{HasEventSenderProxy, EventSenderProxy, Publish, AppEngine};
+pub use crate::event::{AssociatedSubEvent, Event, AssociatedPubEvent, HandleEvent, AsyncHandleEvent};
+pub use crate::app::{HasEventSenderProxy, EventSenderProxy, Publish, AppEngine, Handle, HasShutdownSignal, ShutdownSignal};
+pub use crate::async_app::{HasAsyncEventSenderProxy, AsyncEventSenderProxy, AsyncPublish, AsyncAppEngine, AsyncHandle};
+pub use crate::metrics::{metrics, Metrics, MetricsConfig, MetricsSnapshot, TypeMetricsSnapshot};
+pub use crate::registry::{deserializer_for, EventTypeEntry};
+pub use crate::store::{event_store, set_event_store, EventStore, SledEventStore, StoredEvent};
 pub use crate::{pub_event, sub_event};
\ No newline at end of file
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::event::Event;
+
+/// Deserializes a type-erased blob back into a concrete, boxed [`Event`].
+/// `#[derive(EventType)]` submits one of these per event type via
+/// `inventory::submit!`, so the durable log and the network relay can route
+/// incoming bytes back to a concrete type by name alone, without either
+/// side needing a compile-time list of every event type in the system.
+pub struct EventTypeEntry {
+    pub type_name: &'static str,
+    pub deserialize: fn(&[u8]) -> Result<Arc<dyn Event + Send + Sync>, String>,
+}
+
+inventory::collect!(EventTypeEntry);
+
+/// Looks up the deserializer submitted for `type_name`, returning `None` if
+/// that event type was never compiled into this binary -- e.g. a stored
+/// record or a remote peer's event this process doesn't know about.
+pub fn deserializer_for(type_name: &str) -> Option<fn(&[u8]) -> Result<Arc<dyn Event + Send + Sync>, String>> {
+    inventory::iter::<EventTypeEntry>()
+        .into_iter()
+        .find(|entry| entry.type_name == type_name)
+        .map(|entry| entry.deserialize)
+}
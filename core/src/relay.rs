@@ -0,0 +1,285 @@
+use std::any::TypeId;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::app::{AppEngine, EventSenderProxy, HasEventSenderProxy, HasShutdownSignal, PubApp, Publish, ShutdownSignal, SubApp, SubscriberHandle};
+use crate::event::{AssociatedPubEvent, AssociatedSubEvent, Event, HandleEvent};
+use crate::registry::deserializer_for;
+
+/// Wire format for one event crossing the network relay: a stable string
+/// type name -- stable across binaries and rebuilds, unlike `TypeId` -- plus
+/// the same serialized payload `Event::serialize` produces for the durable
+/// log.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RelayEnvelope {
+    type_name: String,
+    timestamp_millis: u128,
+    payload: Vec<u8>,
+}
+
+fn write_frame(stream: &mut TcpStream, envelope: &RelayEnvelope) -> io::Result<()> {
+    let encoded = bincode::serialize(envelope).expect("failed to encode relay envelope");
+    stream.write_all(&(encoded.len() as u32).to_be_bytes())?;
+    stream.write_all(&encoded)
+}
+
+fn read_frame(stream: &mut TcpStream) -> io::Result<RelayEnvelope> {
+    let mut len_bytes = [0u8; 4];
+    stream.read_exact(&mut len_bytes)?;
+    let len = u32::from_be_bytes(len_bytes) as usize;
+    let mut buf = vec![0u8; len];
+    stream.read_exact(&mut buf)?;
+    bincode::deserialize(&buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0)
+}
+
+/// `PubApp` that connects out to a `RemoteSubscriber` on another node and
+/// republishes whatever it relays as if produced locally, so the rest of the
+/// graph -- and every `SubApp` on this process -- can't tell the events came
+/// over a socket. Reconnects with a fixed backoff if the peer isn't up yet
+/// or the connection drops.
+struct RemotePublisher {
+    addr: String,
+    types: Vec<TypeId>,
+    sender_proxy: EventSenderProxy,
+    shutdown: ShutdownSignal,
+}
+
+impl HasEventSenderProxy for RemotePublisher {
+    fn get_event_sender_proxy(&mut self) -> &mut EventSenderProxy {
+        &mut self.sender_proxy
+    }
+}
+
+impl HasShutdownSignal for RemotePublisher {
+    fn get_shutdown_signal(&mut self) -> &mut ShutdownSignal {
+        &mut self.shutdown
+    }
+}
+
+impl AssociatedPubEvent for RemotePublisher {
+    fn get_associated_pub_event_ids(&self) -> Vec<TypeId> {
+        self.types.clone()
+    }
+}
+
+impl Publish for RemotePublisher {
+    fn publish_event(&mut self) {
+        while !self.shutdown.is_shutting_down() {
+            let mut stream = match TcpStream::connect(&self.addr) {
+                Ok(stream) => stream,
+                Err(_) => {
+                    thread::sleep(Duration::from_secs(1));
+                    continue;
+                }
+            };
+            // A bounded read timeout means an idle-but-connected peer can't
+            // park this thread in `read_exact` forever -- without it,
+            // `Handle::shutdown()` followed by `Handle::join()` could hang.
+            if stream.set_read_timeout(Some(Duration::from_millis(200))).is_err() {
+                continue;
+            }
+            while !self.shutdown.is_shutting_down() {
+                let envelope = match read_frame(&mut stream) {
+                    Ok(envelope) => envelope,
+                    Err(e) if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => {
+                        continue;
+                    }
+                    Err(_) => break, // peer dropped the connection; reconnect
+                };
+                let Some(deserialize) = deserializer_for(&envelope.type_name) else {
+                    continue;
+                };
+                if let Ok(event) = deserialize(&envelope.payload) {
+                    self.sender_proxy.send_event(event);
+                }
+            }
+        }
+    }
+}
+
+impl PubApp for RemotePublisher {}
+
+/// `SubApp` that forwards every locally published event of its subscribed
+/// types to whichever peers are currently connected on `addr`, so a
+/// `RemotePublisher` on another node can republish them into its own graph.
+struct RemoteSubscriber {
+    types: Vec<TypeId>,
+    sender_proxy: EventSenderProxy,
+    peers: Arc<Mutex<Vec<TcpStream>>>,
+    listener_shutdown: ShutdownSignal,
+    listener_thread: Mutex<Option<thread::JoinHandle<()>>>,
+}
+
+impl HasEventSenderProxy for RemoteSubscriber {
+    fn get_event_sender_proxy(&mut self) -> &mut EventSenderProxy {
+        &mut self.sender_proxy
+    }
+}
+
+impl AssociatedSubEvent for RemoteSubscriber {
+    fn get_associated_sub_event_ids(&self) -> Vec<TypeId> {
+        self.types.clone()
+    }
+}
+
+impl AssociatedPubEvent for RemoteSubscriber {}
+
+impl HandleEvent for RemoteSubscriber {
+    fn handle_event(&mut self, event: Arc<dyn Event + Sync + Send>) {
+        let envelope = RelayEnvelope {
+            type_name: event.type_name().to_string(),
+            timestamp_millis: now_millis(),
+            payload: event.serialize(),
+        };
+        let mut peers = self.peers.lock().expect("relay peers lock poisoned");
+        peers.retain_mut(|stream| write_frame(stream, &envelope).is_ok());
+    }
+}
+
+impl SubApp for RemoteSubscriber {
+    /// Stops the background listener thread and waits for it to exit, so
+    /// `Handle::join()` -- which joins this `SubApp`'s own thread only after
+    /// `on_shutdown` returns -- doesn't leave it running past engine
+    /// shutdown.
+    fn on_shutdown(&mut self) {
+        self.listener_shutdown.trigger();
+        if let Some(handle) = self.listener_thread.lock().expect("relay listener handle lock poisoned").take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Accepts connections on `addr` in the background and hands each one to
+/// `peers`, so `RemoteSubscriber::handle_event` can broadcast to every peer
+/// that's connected so far. Polls a non-blocking listener instead of
+/// blocking in `accept` so it notices `shutdown` promptly instead of being
+/// leaked past engine shutdown.
+fn accept_peers(addr: impl ToSocketAddrs + Send + 'static, peers: Arc<Mutex<Vec<TcpStream>>>, shutdown: ShutdownSignal) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let listener = match TcpListener::bind(addr) {
+            Ok(listener) => listener,
+            Err(_) => return,
+        };
+        if listener.set_nonblocking(true).is_err() {
+            return;
+        }
+        while !shutdown.is_shutting_down() {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    // Without a write timeout, a stalled-but-connected peer
+                    // leaves `write_frame`'s `write_all` blocked forever in
+                    // `handle_event` -- stalling the whole SubscriberRunner
+                    // thread (and its shutdown check) on one dead peer,
+                    // exactly the head-of-line blocking the credit system
+                    // elsewhere in this series exists to avoid.
+                    if stream.set_write_timeout(Some(Duration::from_millis(200))).is_err() {
+                        continue;
+                    }
+                    peers.lock().expect("relay peers lock poisoned").push(stream);
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Err(_) => break,
+            }
+        }
+    })
+}
+
+impl AppEngine {
+    /// Connects to `addr` -- where a peer's `add_remote_subscriber` is
+    /// listening -- and republishes every relayed event of `types` as if it
+    /// had been produced by a local `PubApp`.
+    pub fn add_remote_publisher(&mut self, addr: impl Into<String>, types: &[TypeId]) {
+        let publisher = RemotePublisher {
+            addr: addr.into(),
+            types: types.to_vec(),
+            sender_proxy: EventSenderProxy::new(),
+            shutdown: ShutdownSignal::new(),
+        };
+        self.add_pub_app(Box::new(publisher));
+    }
+
+    /// Listens on `addr` and forwards every locally published event of
+    /// `types` to whichever peers have connected -- exactly like a local
+    /// `SubApp` that happens to ship its events over a socket instead of
+    /// handling them in-process.
+    pub fn add_remote_subscriber(&mut self, addr: impl ToSocketAddrs + Send + 'static, types: &[TypeId]) -> SubscriberHandle {
+        let peers = Arc::new(Mutex::new(Vec::new()));
+        let listener_shutdown = ShutdownSignal::new();
+        let listener_thread = accept_peers(addr, peers.clone(), listener_shutdown.clone());
+        let subscriber = RemoteSubscriber {
+            types: types.to_vec(),
+            sender_proxy: EventSenderProxy::new(),
+            peers,
+            listener_shutdown,
+            listener_thread: Mutex::new(Some(listener_thread)),
+        };
+        self.add_sub_app(Box::new(subscriber))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Real loopback socket pair instead of a mock, so the test exercises the
+    /// actual length-prefixed framing `write_frame`/`read_frame` agree on.
+    fn loopback_pair() -> (TcpStream, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind loopback listener");
+        let addr = listener.local_addr().unwrap();
+        let client = TcpStream::connect(addr).expect("connect loopback client");
+        let (server, _) = listener.accept().expect("accept loopback client");
+        (client, server)
+    }
+
+    #[test]
+    fn write_then_read_frame_round_trips_the_envelope() {
+        let (mut writer, mut reader) = loopback_pair();
+        let envelope = RelayEnvelope {
+            type_name: "Price".to_string(),
+            timestamp_millis: 1234,
+            payload: vec![1, 2, 3, 4, 5],
+        };
+        write_frame(&mut writer, &envelope).expect("write frame");
+        let received = read_frame(&mut reader).expect("read frame");
+        assert_eq!(received.type_name, envelope.type_name);
+        assert_eq!(received.timestamp_millis, envelope.timestamp_millis);
+        assert_eq!(received.payload, envelope.payload);
+    }
+
+    #[test]
+    fn write_then_read_frame_handles_back_to_back_envelopes() {
+        let (mut writer, mut reader) = loopback_pair();
+        for i in 0..5u128 {
+            let envelope = RelayEnvelope {
+                type_name: "Kline".to_string(),
+                timestamp_millis: i,
+                payload: vec![i as u8],
+            };
+            write_frame(&mut writer, &envelope).expect("write frame");
+        }
+        for i in 0..5u128 {
+            let received = read_frame(&mut reader).expect("read frame");
+            assert_eq!(received.timestamp_millis, i);
+            assert_eq!(received.payload, vec![i as u8]);
+        }
+    }
+
+    #[test]
+    fn read_frame_errors_once_the_peer_closes_mid_frame() {
+        let (mut writer, mut reader) = loopback_pair();
+        // Length prefix promises 10 bytes, but only 2 arrive before the close.
+        writer.write_all(&10u32.to_be_bytes()).expect("write length prefix");
+        writer.write_all(&[1, 2]).expect("write partial payload");
+        drop(writer);
+        assert!(read_frame(&mut reader).is_err());
+    }
+}
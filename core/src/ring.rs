@@ -0,0 +1,203 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Returned by [`RingProducer::push`] when the ring has no free slot. Callers
+/// on a latency-critical path should treat this as "drop and move on" rather
+/// than retrying, since retrying would reintroduce the blocking this
+/// transport exists to avoid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Full;
+
+/// Pads `T` out to a cache line so the producer-owned `tail` and
+/// consumer-owned `head` never share a line and false-share under
+/// contention.
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+/// Wait-free single-producer/single-consumer bounded ring buffer. Capacity is
+/// rounded up to a power of two so index wrapping is a mask instead of a
+/// modulo. The producer only ever writes `tail`, the consumer only ever
+/// writes `head`; `push`/`pop` never block and never allocate.
+struct RingBuffer<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    mask: usize,
+    head: CachePadded<AtomicUsize>,
+    tail: CachePadded<AtomicUsize>,
+}
+
+unsafe impl<T: Send> Send for RingBuffer<T> {}
+unsafe impl<T: Send> Sync for RingBuffer<T> {}
+
+impl<T> RingBuffer<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.next_power_of_two().max(2);
+        let mut slots = Vec::with_capacity(capacity);
+        for _ in 0..capacity {
+            slots.push(UnsafeCell::new(MaybeUninit::uninit()));
+        }
+        RingBuffer {
+            slots: slots.into_boxed_slice(),
+            mask: capacity - 1,
+            head: CachePadded(AtomicUsize::new(0)),
+            tail: CachePadded(AtomicUsize::new(0)),
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.mask + 1
+    }
+}
+
+impl<T> Drop for RingBuffer<T> {
+    fn drop(&mut self) {
+        let mut head = self.head.0.load(Ordering::Relaxed);
+        let tail = self.tail.0.load(Ordering::Relaxed);
+        while head != tail {
+            let idx = head & self.mask;
+            unsafe { (*self.slots[idx].get()).assume_init_drop() };
+            head = head.wrapping_add(1);
+        }
+    }
+}
+
+/// Producer half of a [`RingBuffer`]. Construct a pair with
+/// [`ring_channel`].
+pub struct RingProducer<T> {
+    ring: Arc<RingBuffer<T>>,
+}
+
+/// Consumer half of a [`RingBuffer`]. Construct a pair with
+/// [`ring_channel`].
+pub struct RingConsumer<T> {
+    ring: Arc<RingBuffer<T>>,
+}
+
+unsafe impl<T: Send> Send for RingProducer<T> {}
+unsafe impl<T: Send> Send for RingConsumer<T> {}
+
+impl<T> Clone for RingProducer<T> {
+    /// Clones the producer handle, not the ring. Only meaningful when the
+    /// edge is still fed by a single logical producer (e.g. re-wiring the
+    /// same handle into a proxy's sender registry) -- handing the clone to a
+    /// second concurrent producer breaks the single-producer invariant.
+    fn clone(&self) -> Self {
+        RingProducer { ring: self.ring.clone() }
+    }
+}
+
+impl<T> RingProducer<T> {
+    pub fn capacity(&self) -> usize {
+        self.ring.capacity()
+    }
+
+    /// Enqueues `value`, returning `Err(Full)` without blocking if the
+    /// consumer hasn't caught up.
+    #[inline]
+    pub fn push(&self, value: T) -> Result<(), Full> {
+        let tail = self.ring.tail.0.load(Ordering::Relaxed);
+        let head = self.ring.head.0.load(Ordering::Acquire);
+        if tail.wrapping_sub(head) > self.ring.mask {
+            return Err(Full);
+        }
+        let idx = tail & self.ring.mask;
+        unsafe {
+            (*self.ring.slots[idx].get()).write(value);
+        }
+        self.ring.tail.0.store(tail.wrapping_add(1), Ordering::Release);
+        Ok(())
+    }
+}
+
+impl<T> RingConsumer<T> {
+    /// Dequeues the oldest value, returning `None` without blocking if the
+    /// ring is empty.
+    #[inline]
+    pub fn pop(&self) -> Option<T> {
+        let head = self.ring.head.0.load(Ordering::Relaxed);
+        let tail = self.ring.tail.0.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        let idx = head & self.ring.mask;
+        let value = unsafe { (*self.ring.slots[idx].get()).assume_init_read() };
+        self.ring.head.0.store(head.wrapping_add(1), Ordering::Release);
+        Some(value)
+    }
+}
+
+/// Builds a single-producer/single-consumer ring buffer of at least
+/// `capacity` slots (rounded up to a power of two) and returns its two ends.
+pub fn ring_channel<T>(capacity: usize) -> (RingProducer<T>, RingConsumer<T>) {
+    let ring = Arc::new(RingBuffer::with_capacity(capacity));
+    (RingProducer { ring: ring.clone() }, RingConsumer { ring })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacity_rounds_up_to_a_power_of_two() {
+        let (producer, _consumer) = ring_channel::<u32>(3);
+        assert_eq!(producer.capacity(), 4);
+    }
+
+    #[test]
+    fn pop_returns_values_in_fifo_order() {
+        let (producer, consumer) = ring_channel::<u32>(4);
+        producer.push(1).unwrap();
+        producer.push(2).unwrap();
+        producer.push(3).unwrap();
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), Some(3));
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn push_reports_full_without_overwriting() {
+        let (producer, consumer) = ring_channel::<u32>(2);
+        producer.push(1).unwrap();
+        producer.push(2).unwrap();
+        assert_eq!(producer.push(3), Err(Full));
+        assert_eq!(consumer.pop(), Some(1));
+        // Freeing a slot by popping makes room for exactly one more push.
+        producer.push(3).unwrap();
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), Some(3));
+    }
+
+    #[test]
+    fn survives_many_wraps_around_the_backing_slice() {
+        let (producer, consumer) = ring_channel::<u32>(4);
+        for i in 0..1000u32 {
+            producer.push(i).unwrap();
+            assert_eq!(consumer.pop(), Some(i));
+        }
+    }
+
+    #[test]
+    fn drop_frees_values_still_sitting_in_the_ring() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct DropCounter(Arc<AtomicUsize>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let (producer, consumer) = ring_channel::<DropCounter>(4);
+        producer.push(DropCounter(dropped.clone())).unwrap();
+        producer.push(DropCounter(dropped.clone())).unwrap();
+        // One popped (and already dropped), one left sitting in the ring.
+        consumer.pop();
+        drop(producer);
+        drop(consumer);
+        assert_eq!(dropped.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,214 @@
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::event::Event;
+use crate::registry::deserializer_for;
+
+/// A single durable log entry: enough to replay the original event without
+/// the publisher that produced it, and enough to filter a range scan by
+/// sequence number alone.
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    pub seq: u64,
+    pub type_name: String,
+    pub timestamp_millis: u128,
+    pub payload: Vec<u8>,
+}
+
+/// Durable append-only log backing replay and crash recovery. `AppEngine`
+/// talks to this trait rather than a concrete backend, so the default
+/// `sled`-backed implementation can be swapped out in tests or alternate
+/// deployments.
+pub trait EventStore: Send + Sync {
+    /// Appends a serialized event and returns the sequence number it was
+    /// assigned; sequence numbers are monotonically increasing but not
+    /// necessarily contiguous.
+    fn append(&self, type_name: &str, payload: &[u8]) -> u64;
+
+    /// Returns every stored event with `seq >= from`, in log order.
+    fn range_from(&self, from: u64) -> Vec<StoredEvent>;
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StoredRecord {
+    type_name: String,
+    timestamp_millis: u128,
+    payload: Vec<u8>,
+}
+
+/// `sled`-backed `EventStore`: each entry is keyed by its big-endian sequence
+/// number so `range_from` is a cheap forward scan, with `type_name` and
+/// `timestamp_millis` packed into a small bincode-encoded header ahead of the
+/// raw event payload.
+pub struct SledEventStore {
+    tree: sled::Db,
+}
+
+impl SledEventStore {
+    pub fn open(path: impl AsRef<Path>) -> sled::Result<Self> {
+        Ok(SledEventStore { tree: sled::open(path)? })
+    }
+}
+
+impl EventStore for SledEventStore {
+    fn append(&self, type_name: &str, payload: &[u8]) -> u64 {
+        let seq = self.tree.generate_id().expect("sled id generation failed");
+        let timestamp_millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        let record = StoredRecord {
+            type_name: type_name.to_string(),
+            timestamp_millis,
+            payload: payload.to_vec(),
+        };
+        let encoded = bincode::serialize(&record).expect("failed to encode stored event");
+        self.tree.insert(seq.to_be_bytes(), encoded).expect("sled append failed");
+        seq
+    }
+
+    fn range_from(&self, from: u64) -> Vec<StoredEvent> {
+        self.tree
+            .range(from.to_be_bytes()..)
+            .filter_map(|entry| entry.ok())
+            .filter_map(|(key, value)| {
+                let seq = u64::from_be_bytes(key.as_ref().try_into().ok()?);
+                let record: StoredRecord = bincode::deserialize(&value).ok()?;
+                Some(StoredEvent {
+                    seq,
+                    type_name: record.type_name,
+                    timestamp_millis: record.timestamp_millis,
+                    payload: record.payload,
+                })
+            })
+            .collect()
+    }
+}
+
+static EVENT_STORE: OnceLock<Arc<dyn EventStore>> = OnceLock::new();
+
+/// Installs the process-wide durable log. Must be called before
+/// `AppEngine::run` for replay or crash recovery to take effect;
+/// `EventSenderProxy::send_event` silently skips persistence when no store
+/// has been installed, matching the opt-in, zero-cost-when-unused feel of
+/// [`crate::metrics::metrics`].
+pub fn set_event_store(store: Arc<dyn EventStore>) {
+    let _ = EVENT_STORE.set(store);
+}
+
+pub fn event_store() -> Option<&'static Arc<dyn EventStore>> {
+    EVENT_STORE.get()
+}
+
+/// Replays every stored event at or after `seq`, in log order, through
+/// `deliver`. Used by `AppEngine::run`'s `replay_from` to feed history to
+/// subscribers before live traffic begins; does nothing if no store was
+/// installed or a stored event's type was never registered by an
+/// `EventType` derive in this binary.
+pub fn replay_from(seq: u64, mut deliver: impl FnMut(Arc<dyn Event + Send + Sync>)) {
+    let Some(store) = event_store() else { return };
+    for stored in store.range_from(seq) {
+        let Some(deserialize) = deserializer_for(&stored.type_name) else {
+            continue;
+        };
+        if let Ok(event) = deserialize(&stored.payload) {
+            deliver(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct TestStoredEvent {
+        value: u32,
+    }
+
+    impl Event for TestStoredEvent {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn type_name(&self) -> &'static str {
+            "TestStoredEvent"
+        }
+
+        fn serialize(&self) -> Vec<u8> {
+            bincode::serialize(self).expect("failed to serialize event")
+        }
+    }
+
+    inventory::submit! {
+        EventTypeEntry {
+            type_name: "TestStoredEvent",
+            deserialize: |bytes: &[u8]| {
+                bincode::deserialize::<TestStoredEvent>(bytes)
+                    .map(|event| Arc::new(event) as Arc<dyn Event + Send + Sync>)
+                    .map_err(|e| e.to_string())
+            },
+        }
+    }
+
+    /// In-memory `EventStore` double so replay can be tested without `sled`.
+    #[derive(Default)]
+    struct MemoryStore {
+        entries: Mutex<Vec<StoredEvent>>,
+        next_seq: Mutex<u64>,
+    }
+
+    impl EventStore for MemoryStore {
+        fn append(&self, type_name: &str, payload: &[u8]) -> u64 {
+            let mut next_seq = self.next_seq.lock().unwrap();
+            let seq = *next_seq;
+            *next_seq += 1;
+            self.entries.lock().unwrap().push(StoredEvent {
+                seq,
+                type_name: type_name.to_string(),
+                timestamp_millis: 0,
+                payload: payload.to_vec(),
+            });
+            seq
+        }
+
+        fn range_from(&self, from: u64) -> Vec<StoredEvent> {
+            self.entries.lock().unwrap().iter().filter(|e| e.seq >= from).cloned().collect()
+        }
+    }
+
+    #[test]
+    fn range_from_only_returns_entries_at_or_after_the_given_seq() {
+        let store = MemoryStore::default();
+        store.append("TestStoredEvent", &[1]);
+        store.append("TestStoredEvent", &[2]);
+        store.append("TestStoredEvent", &[3]);
+        let from_one = store.range_from(1);
+        assert_eq!(from_one.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn stored_events_deserialize_back_in_log_order() {
+        let store = MemoryStore::default();
+        let first = TestStoredEvent { value: 1 };
+        let second = TestStoredEvent { value: 2 };
+        store.append("TestStoredEvent", &first.serialize());
+        store.append("TestStoredEvent", &second.serialize());
+
+        let mut delivered = Vec::new();
+        let events = store.range_from(0);
+        for stored in events {
+            let deserialize = deserializer_for(&stored.type_name).expect("TestStoredEvent is registered");
+            let event = deserialize(&stored.payload).expect("valid payload");
+            delivered.push(event.as_any().downcast_ref::<TestStoredEvent>().unwrap().value);
+        }
+        assert_eq!(delivered, vec![1, 2]);
+    }
+
+    #[test]
+    fn deserializer_for_returns_none_for_unregistered_event_types() {
+        assert!(deserializer_for("SomeTypeNobodyRegistered").is_none());
+    }
+}
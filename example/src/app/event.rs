@@ -1,7 +1,8 @@
 use chrono::{DateTime, Utc};
 use event_flow::macros::EventType;
+use serde::{Deserialize, Serialize};
 
-#[derive(EventType)]
+#[derive(EventType, Serialize, Deserialize)]
 pub struct Kline{
     pub symbol: String,
     pub open: f32,
@@ -24,7 +25,7 @@ impl Kline {
     }
 }
 
-#[derive(EventType)]
+#[derive(EventType, Serialize, Deserialize)]
 pub struct Price {
     pub symbol: String,
     pub price: f32,
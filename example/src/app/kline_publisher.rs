@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
-use event_flow::app::{EventSenderProxy, HasEventSenderProxy, Publish};
+use event_flow::app::{EventSenderProxy, HasEventSenderProxy, HasShutdownSignal, Publish, ShutdownSignal};
 
 use crate::app::event::Kline;
 use event_flow::macros::PubApp;
@@ -10,12 +10,14 @@ use event_flow::macros::PubApp;
 #[pub_event(Kline)]
 pub struct KlinePublisher {
     sender_proxy: EventSenderProxy,
+    shutdown: ShutdownSignal,
 }
 
 impl KlinePublisher {
     pub fn new() -> KlinePublisher {
         KlinePublisher {
-            sender_proxy: EventSenderProxy::new()
+            sender_proxy: EventSenderProxy::new(),
+            shutdown: ShutdownSignal::new(),
         }
     }
 }
@@ -26,9 +28,15 @@ impl HasEventSenderProxy for KlinePublisher {
     }
 }
 
+impl HasShutdownSignal for KlinePublisher {
+    fn get_shutdown_signal(&mut self) -> &mut ShutdownSignal {
+        &mut self.shutdown
+    }
+}
+
 impl Publish for KlinePublisher {
     fn publish_event(&mut self) {
-        loop {
+        while !self.shutdown.is_shutting_down() {
             let kline = Arc::new(Kline::new("BTCUSDT".to_string(), 1.1, 1.2, 1.0, 1.3));
             self.sender_proxy.send_event(kline);
             let duration = Duration::from_secs(1);
@@ -7,6 +7,7 @@ use event_flow::macros::SubApp;
 
 #[derive(SubApp)]
 #[sub_event(Price)]
+#[sub_filter(Price, symbol = "BTCUSDT")]
 pub struct PriceConsumerApp {
     sender_proxy: EventSenderProxy,
 }
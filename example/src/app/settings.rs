@@ -1,17 +1,25 @@
+use arc_swap::ArcSwap;
 use config::Config;
 use lazy_static::lazy_static;
-use std::sync::RwLock;
+use std::sync::Arc;
 
-lazy_static! {
-    static ref SETTINGS: RwLock<Config> = {
-    let settings = Config::builder()
+fn load_settings() -> Config {
+    Config::builder()
         .add_source(config::File::with_name("example/Settings"))
         .build()
-        .unwrap();
-         RwLock::new(settings)
-    };
+        .unwrap()
+}
+
+lazy_static! {
+    static ref SETTINGS: ArcSwap<Config> = ArcSwap::from_pointee(load_settings());
+}
+
+pub fn get_settings() -> Arc<Config> {
+    SETTINGS.load_full()
 }
 
-pub fn get_settings() -> Config {
-    SETTINGS.read().expect("Failed to read settings").clone()
+/// Re-reads `example/Settings` and atomically swaps it in, so a running
+/// `AppEngine` picks up config changes without a restart.
+pub fn reload_settings() {
+    SETTINGS.store(Arc::new(load_settings()));
 }
\ No newline at end of file
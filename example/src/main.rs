@@ -1,5 +1,8 @@
 mod app;
 
+use std::any::TypeId;
+
+use app::event::Kline;
 use app::kline_publisher::KlinePublisher;
 use app::market_maker_app::MarketMakerApp;
 use event_flow::app::AppEngine;
@@ -7,8 +10,16 @@ use crate::app::price_consumer::PriceConsumerApp;
 
 fn main() {
     let mut engine = AppEngine::new();
-    engine.add_sub_app(Box::new(MarketMakerApp::new()));
+    // `KlinePublisher` is the only publisher of `Kline`, so this edge
+    // qualifies for the lock-free ring transport instead of the default
+    // crossbeam channel.
+    engine.add_sub_app_with_ring_transport(Box::new(MarketMakerApp::new()), &[TypeId::of::<Kline>()], 1024);
     engine.add_pub_app(Box::new(KlinePublisher::new()));
     engine.add_sub_app(Box::new(PriceConsumerApp::new()));
-    engine.run();
+    // `run` returns as soon as the app threads are spawned; block on `join`
+    // so the process keeps running them instead of exiting immediately.
+    let handle = engine.run();
+    for panic in handle.join() {
+        eprintln!("{panic}");
+    }
 }
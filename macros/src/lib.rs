@@ -1,5 +1,5 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{
     parse_macro_input,
     parse::{Parse, ParseStream},
@@ -7,6 +7,10 @@ use syn::{
 };
 
 
+/// Implements `Event` for a struct and registers it for persistence and
+/// cross-process routing. The struct must also derive
+/// `serde::Serialize`/`serde::Deserialize`, since `serialize`/the registered
+/// deserializer go through `bincode`.
 #[proc_macro_derive(EventType)]
 pub fn build_event_type(_item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(_item as DeriveInput);
@@ -18,11 +22,106 @@ pub fn build_event_type(_item: TokenStream) -> TokenStream {
             fn as_any(&self) -> &dyn std::any::Any {
                 self
             }
+
+            fn type_name(&self) -> &'static str {
+                stringify!(#name)
+            }
+
+            fn serialize(&self) -> Vec<u8> {
+                bincode::serialize(self).expect("failed to serialize event")
+            }
+        }
+
+        event_flow::inventory::submit! {
+            event_flow::core::registry::EventTypeEntry {
+                type_name: stringify!(#name),
+                deserialize: |bytes: &[u8]| {
+                    bincode::deserialize::<#name>(bytes)
+                        .map(|event| std::sync::Arc::new(event) as std::sync::Arc<dyn event_flow::core::event::Event + Send + Sync>)
+                        .map_err(|e| e.to_string())
+                },
+            }
         }
     };
     TokenStream::from(expanded)
 }
 
+/// Comparison used by one `field <op> value` condition in a `sub_filter`
+/// spec. Defaults to `Eq` when a condition is written as `field = value`,
+/// but numeric fields (e.g. `price`) can use any of the others for range
+/// filters like `price > 100.0`.
+#[derive(Clone, Copy)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Parse for CompareOp {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        // Longer punctuation first: `<=`/`>=`/`==`/`!=` would otherwise be
+        // consumed as `<`/`>`/`=` followed by a dangling `=`.
+        if input.peek(Token![==]) {
+            input.parse::<Token![==]>()?;
+            Ok(CompareOp::Eq)
+        } else if input.peek(Token![!=]) {
+            input.parse::<Token![!=]>()?;
+            Ok(CompareOp::Ne)
+        } else if input.peek(Token![<=]) {
+            input.parse::<Token![<=]>()?;
+            Ok(CompareOp::Le)
+        } else if input.peek(Token![>=]) {
+            input.parse::<Token![>=]>()?;
+            Ok(CompareOp::Ge)
+        } else if input.peek(Token![<]) {
+            input.parse::<Token![<]>()?;
+            Ok(CompareOp::Lt)
+        } else if input.peek(Token![>]) {
+            input.parse::<Token![>]>()?;
+            Ok(CompareOp::Gt)
+        } else {
+            input.parse::<Token![=]>()?;
+            Ok(CompareOp::Eq)
+        }
+    }
+}
+
+impl ToTokens for CompareOp {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(match self {
+            CompareOp::Eq => quote! { == },
+            CompareOp::Ne => quote! { != },
+            CompareOp::Lt => quote! { < },
+            CompareOp::Le => quote! { <= },
+            CompareOp::Gt => quote! { > },
+            CompareOp::Ge => quote! { >= },
+        });
+    }
+}
+
+struct FilterSpec {
+    event_type: Ident,
+    conditions: Vec<(Ident, CompareOp, syn::Lit)>,
+}
+
+impl Parse for FilterSpec {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let event_type: Ident = input.parse()?;
+        let mut conditions = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token!(,)>()?;
+            let field: Ident = input.parse()?;
+            let op: CompareOp = input.parse()?;
+            let value: syn::Lit = input.parse()?;
+            conditions.push((field, op, value));
+        }
+        Ok(FilterSpec { event_type, conditions })
+    }
+}
+
 struct EParser {
     v: Vec<Ident>,
 }
@@ -45,6 +144,36 @@ impl Parse for EParser {
     }
 }
 
+fn get_filters(ast: &DeriveInput) -> Vec<FilterSpec> {
+    let mut target: Vec<FilterSpec> = vec![];
+    for attr in &ast.attrs {
+        if attr.path().is_ident("sub_filter") {
+            match &attr.meta {
+                Meta::List(list) => {
+                    let parsed: FilterSpec = list.parse_args().unwrap();
+                    target.push(parsed);
+                }
+                _ => panic!("Incorrect format for using the `sub_filter` attribute."),
+            }
+        }
+    }
+    target
+}
+
+/// Groups `#[sub_filter(EventType, ...)]` specs by event type, preserving
+/// the order each type was first seen, so the caller can OR every group for
+/// a type together instead of only acting on the first matching attribute.
+fn group_filters_by_event_type(filters: &[FilterSpec]) -> Vec<(Ident, Vec<Vec<(Ident, CompareOp, syn::Lit)>>)> {
+    let mut grouped: Vec<(Ident, Vec<Vec<(Ident, CompareOp, syn::Lit)>>)> = Vec::new();
+    for filter in filters.iter() {
+        match grouped.iter_mut().find(|(event_type, _)| *event_type == filter.event_type) {
+            Some((_, groups)) => groups.push(filter.conditions.clone()),
+            None => grouped.push((filter.event_type.clone(), vec![filter.conditions.clone()])),
+        }
+    }
+    grouped
+}
+
 fn get_event(ast: &DeriveInput, name: &str) -> Vec<Ident> {
     let mut target: Vec<Ident> = vec![];
     for attr in &ast.attrs {
@@ -77,7 +206,7 @@ pub fn pub_app_derive(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
-#[proc_macro_derive(SubApp, attributes(sub_event, pub_event))]
+#[proc_macro_derive(SubApp, attributes(sub_event, pub_event, sub_filter))]
 pub fn sub_app_derive(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
     let sub_target: Vec<Ident> = get_event(&ast, "sub_event");
@@ -85,12 +214,43 @@ pub fn sub_app_derive(input: TokenStream) -> TokenStream {
         panic!("The `sub_event` attribute must be used to set at least one target.");
     }
     let pub_target: Vec<Ident> = get_event(&ast, "pub_event");
+    let filters = get_filters(&ast);
     let name = ast.ident;
+    let event_filter_method = if filters.is_empty() {
+        quote! {}
+    } else {
+        // Multiple `#[sub_filter(SameType, ...)]` attributes on one event
+        // type are an allow-list: an event should pass if it matches *any*
+        // of them. Group by event type and OR the groups together instead of
+        // returning on the first match, which would make every attribute
+        // after the first dead code.
+        let grouped = group_filters_by_event_type(&filters);
+        let filter_arms = grouped.iter().map(|(event_type, condition_groups)| {
+            let group_exprs = condition_groups.iter().map(|conditions| {
+                let checks = conditions.iter().map(|(field, op, value)| {
+                    quote! { typed.#field #op #value }
+                });
+                quote! { (true #(&& (#checks))*) }
+            });
+            quote! {
+                if let Some(typed) = event.as_any().downcast_ref::<#event_type>() {
+                    return #(#group_exprs)||*;
+                }
+            }
+        });
+        quote! {
+            fn event_filter(&self, event: &dyn event_flow::core::event::Event) -> bool {
+                #(#filter_arms)*
+                true
+            }
+        }
+    };
     let expanded = quote! {
         impl event_flow::core::event::AssociatedSubEvent for #name {
             fn get_associated_sub_event_ids(&self) -> Vec<std::any::TypeId> {
                 vec![#(std::any::TypeId::of::<#sub_target>()),*]
             }
+            #event_filter_method
         }
         impl event_flow::core::event::AssociatedPubEvent for #name {
             fn get_associated_pub_event_ids(&self) -> Vec<std::any::TypeId> {
@@ -100,4 +260,73 @@ pub fn sub_app_derive(input: TokenStream) -> TokenStream {
         impl event_flow::core::app::SubApp for #name {}
     };
     expanded.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+
+    fn parse_filter(input: &str) -> FilterSpec {
+        syn::parse_str(input).expect("valid sub_filter spec")
+    }
+
+    fn field_names(conditions: &[(Ident, CompareOp, syn::Lit)]) -> Vec<String> {
+        conditions.iter().map(|(field, _, _)| field.to_string()).collect()
+    }
+
+    #[test]
+    fn single_spec_parses_its_event_type_and_conditions() {
+        let spec = parse_filter(r#"Price, symbol = "BTCUSDT""#);
+        assert_eq!(spec.event_type.to_string(), "Price");
+        assert_eq!(field_names(&spec.conditions), vec!["symbol"]);
+    }
+
+    #[test]
+    fn multiple_conditions_on_one_spec_are_all_captured() {
+        let spec = parse_filter(r#"Price, symbol = "BTCUSDT", price = 100.0"#);
+        assert_eq!(field_names(&spec.conditions), vec!["symbol", "price"]);
+    }
+
+    #[test]
+    fn specs_for_the_same_event_type_are_grouped_together() {
+        let filters = vec![
+            parse_filter(r#"Price, symbol = "BTCUSDT""#),
+            parse_filter(r#"Price, symbol = "ETHUSDT""#),
+        ];
+        let grouped = group_filters_by_event_type(&filters);
+        assert_eq!(grouped.len(), 1);
+        let (event_type, groups) = &grouped[0];
+        assert_eq!(event_type.to_string(), "Price");
+        // Both specs must survive as separate OR'd groups, not overwrite or
+        // merge into one -- this is exactly the bug the OR-grouping fix
+        // addressed.
+        assert_eq!(groups.len(), 2);
+        assert_eq!(field_names(&groups[0]), vec!["symbol"]);
+        assert_eq!(groups[0][0].2.to_token_stream().to_string(), "\"BTCUSDT\"");
+        assert_eq!(groups[1][0].2.to_token_stream().to_string(), "\"ETHUSDT\"");
+    }
+
+    #[test]
+    fn comparison_operators_are_parsed_for_numeric_range_filters() {
+        let spec = parse_filter("Price, price > 100.0");
+        assert_eq!(field_names(&spec.conditions), vec!["price"]);
+        assert!(matches!(spec.conditions[0].1, CompareOp::Gt));
+        assert_eq!(spec.conditions[0].2.to_token_stream().to_string(), "100.0");
+
+        let spec = parse_filter("Price, price <= 50.0, price >= 10.0, price != 25.0");
+        let ops: Vec<_> = spec.conditions.iter().map(|(_, op, _)| *op).collect();
+        assert!(matches!(ops[0], CompareOp::Le));
+        assert!(matches!(ops[1], CompareOp::Ge));
+        assert!(matches!(ops[2], CompareOp::Ne));
+    }
+
+    #[test]
+    fn specs_for_different_event_types_stay_in_separate_groups() {
+        let filters = vec![parse_filter(r#"Price, symbol = "BTCUSDT""#), parse_filter(r#"Kline, symbol = "BTCUSDT""#)];
+        let grouped = group_filters_by_event_type(&filters);
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].0.to_string(), "Price");
+        assert_eq!(grouped[1].0.to_string(), "Kline");
+    }
 }
\ No newline at end of file